@@ -0,0 +1,122 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_linter::importer::ImportRequest;
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{Expr, StmtFunctionDef};
+use ruff_python_semantic::analyze::divergence;
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for functions that always diverge (they always raise, call `sys.exit`, or loop
+/// forever) but lack a `NoReturn`/`Never` return annotation.
+///
+/// ## Why is this bad?
+/// Annotating an always-diverging function with `NoReturn` (or `Never`) documents the
+/// guarantee explicitly and lets type checkers narrow the types of code that follows a
+/// call to it -- for example, treating an `if`/`else` as exhaustive. Leaving the
+/// annotation off (or annotating `-> None`, which is misleading for a function that never
+/// returns) forces every caller to rediscover the guarantee by reading the body.
+///
+/// ## Example
+/// ```python
+/// def fail(msg: str):
+///     raise RuntimeError(msg)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from typing import NoReturn
+///
+///
+/// def fail(msg: str) -> NoReturn:
+///     raise RuntimeError(msg)
+/// ```
+#[violation]
+pub struct MissingNoReturnAnnotation {
+    annotation: &'static str,
+}
+
+impl Violation for MissingNoReturnAnnotation {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { annotation } = self;
+        format!("Function never returns; consider annotating it `-> {annotation}`")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { annotation } = self;
+        Some(format!("Add `-> {annotation}` annotation"))
+    }
+}
+
+/// TC010
+pub(crate) fn missing_no_return_annotation(checker: &mut Checker, function_def: &StmtFunctionDef) {
+    // Skip functions that already have a (non-`None`) return annotation. Those annotated
+    // `-> NoReturn`/`-> Never` are the purview of `no-return-can-return` instead; any other
+    // annotation is left alone.
+    if let Some(returns) = function_def.returns.as_deref() {
+        if !is_none_literal(returns) {
+            return;
+        }
+    }
+
+    // `block_diverges` checks whether *any* statement in the body diverges, not just the
+    // last one, so a function that diverges but has unreachable dead code trailing the
+    // `raise` (e.g. `raise ValueError(); print("dead")`) is still flagged here.
+    let extra_diverging_calls = &checker.settings().flake8_type_checking.extra_diverging_calls;
+    if !divergence::block_diverges(&function_def.body, checker.semantic(), extra_diverging_calls) {
+        return;
+    }
+
+    let (annotation, module) = bottom_type_spelling(checker);
+    let mut diagnostic = Diagnostic::new(
+        MissingNoReturnAnnotation { annotation },
+        function_def
+            .returns
+            .as_ref()
+            .map_or(function_def.name.range(), Ranged::range),
+    );
+
+    if let Some(edit) = checker
+        .importer()
+        .get_or_import_symbol(
+            &ImportRequest::import_from(module, annotation),
+            function_def.start(),
+            checker.semantic(),
+        )
+        .ok()
+        .map(|(import_edit, binding)| {
+            let returns_edit = match &function_def.returns {
+                Some(returns) => Edit::range_replacement(binding.clone(), returns.range()),
+                None => Edit::insertion(
+                    format!(" -> {binding}"),
+                    function_def.parameters.range().end(),
+                ),
+            };
+            (import_edit, returns_edit)
+        })
+    {
+        let (import_edit, returns_edit) = edit;
+        diagnostic.set_fix(Fix::safe_edits(import_edit, [returns_edit]));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+fn is_none_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::NoneLiteral(_))
+}
+
+/// Picks `Never`, importable directly from `typing`, on target versions that support it
+/// (Python 3.11+); falls back to the more broadly supported `NoReturn` otherwise.
+fn bottom_type_spelling(checker: &Checker) -> (&'static str, &'static str) {
+    if checker.settings().target_version >= ruff_python_ast::PythonVersion::Py311 {
+        ("Never", "typing")
+    } else {
+        ("NoReturn", "typing")
+    }
+}
@@ -6,7 +6,15 @@ pub(crate) use typing_only_runtime_import::{
     typing_only_runtime_import, TypingOnlyFirstPartyImport, TypingOnlyStandardLibraryImport,
     TypingOnlyThirdPartyImport,
 };
+pub(crate) use missing_no_return_annotation::{
+    missing_no_return_annotation, MissingNoReturnAnnotation,
+};
+pub(crate) use no_return_can_return::{no_return_can_return, NoReturnCanReturn};
+pub(crate) use unreachable_code::{unreachable_code, UnreachableCode};
 
 mod empty_type_checking_block;
+mod missing_no_return_annotation;
+mod no_return_can_return;
 mod runtime_import_in_type_checking_block;
 mod typing_only_runtime_import;
+mod unreachable_code;
@@ -0,0 +1,105 @@
+use ruff_diagnostics::{Diagnostic, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::StmtFunctionDef;
+use ruff_python_semantic::analyze::divergence;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for functions annotated `-> NoReturn` or `-> Never` that can, in fact, return.
+///
+/// ## Why is this bad?
+/// A return annotation of `NoReturn` or `Never` is a promise to callers that the function
+/// never returns control to them -- it always raises, exits the process, or otherwise
+/// diverges. If the body contains a reachable `return <value>` or `yield`, or if control
+/// can fall off the end of the function, that promise is broken, and any code relying on
+/// the annotation (e.g. to justify skipping a branch) is unsound.
+///
+/// ## Example
+/// ```python
+/// from typing import NoReturn
+///
+///
+/// def fail(condition: bool) -> NoReturn:
+///     if condition:
+///         raise RuntimeError("failed")
+///     # falls through if `condition` is `False`
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from typing import NoReturn
+///
+///
+/// def fail(condition: bool) -> NoReturn:
+///     if condition:
+///         raise RuntimeError("failed")
+///     raise RuntimeError("failed")
+/// ```
+#[violation]
+pub struct NoReturnCanReturn {
+    annotation: &'static str,
+    falls_through: bool,
+}
+
+impl Violation for NoReturnCanReturn {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::None;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            annotation,
+            falls_through,
+        } = self;
+        if *falls_through {
+            format!(
+                "Function is annotated `-> {annotation}`, but control can fall off the end \
+                 of the function body"
+            )
+        } else {
+            format!(
+                "Function is annotated `-> {annotation}`, but it can return or yield a value"
+            )
+        }
+    }
+}
+
+/// TC009
+pub(crate) fn no_return_can_return(
+    checker: &mut Checker,
+    function_def: &StmtFunctionDef,
+    annotation: &'static str,
+) {
+    let extra_diverging_calls = &checker.settings().flake8_type_checking.extra_diverging_calls;
+    let semantic = checker.semantic();
+
+    if let Some(reachable) =
+        divergence::find_reachable_return_value(&function_def.body, semantic, extra_diverging_calls)
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            NoReturnCanReturn {
+                annotation,
+                falls_through: false,
+            },
+            reachable.range(),
+        ));
+        return;
+    }
+
+    // `block_diverges` checks whether *any* statement in the body diverges, not just the
+    // last one, so a dead statement after a `raise` (e.g. a stray `return`) doesn't cause
+    // a false "falls off the end" report here.
+    if !divergence::block_diverges(&function_def.body, semantic, extra_diverging_calls) {
+        checker.diagnostics.push(Diagnostic::new(
+            NoReturnCanReturn {
+                annotation,
+                falls_through: true,
+            },
+            function_def
+                .returns
+                .as_ref()
+                .map_or(function_def.range(), |returns| returns.range()),
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use ruff_diagnostics::{Diagnostic, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::Stmt;
+use ruff_python_semantic::analyze::divergence;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for statements that can never execute because a preceding statement in the same
+/// block always diverges.
+///
+/// ## Why is this bad?
+/// A statement is unreachable if the statement before it always `raise`s, always calls a
+/// function that never returns (e.g. one annotated `-> NoReturn`, or `sys.exit`), or is a
+/// `while True:` loop with no reachable `break`. Code after such a statement can never run
+/// and is typically a mistake -- for example, a misplaced `return`, or leftover code from
+/// a refactor.
+///
+/// ## Example
+/// ```python
+/// def fail() -> NoReturn:
+///     raise RuntimeError("unreachable")
+///
+///
+/// def calculate(x: int) -> int:
+///     if x < 0:
+///         fail()
+///         return -1  # this can never run
+///     return x
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def calculate(x: int) -> int:
+///     if x < 0:
+///         fail()
+///     return x
+/// ```
+#[violation]
+pub struct UnreachableCode {
+    reason: &'static str,
+}
+
+impl Violation for UnreachableCode {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::None;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { reason } = self;
+        format!("This statement is unreachable, as a preceding statement {reason}")
+    }
+}
+
+/// TC008
+pub(crate) fn unreachable_code(checker: &mut Checker, body: &[Stmt]) {
+    let extra_diverging_calls = &checker.settings().flake8_type_checking.extra_diverging_calls;
+    let Some((_, divergence, unreachable)) =
+        divergence::find_unreachable(body, checker.semantic(), extra_diverging_calls)
+    else {
+        return;
+    };
+
+    for stmt in unreachable {
+        checker.diagnostics.push(Diagnostic::new(
+            UnreachableCode {
+                reason: divergence.as_str(),
+            },
+            stmt.range(),
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+//! Settings for the `flake8-type-checking` plugin.
+
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    /// Additional dotted-path callables -- beyond `sys.exit`, `os._exit`, `os.abort`,
+    /// `typing.assert_never`, and `typing_extensions.assert_never` -- that should be treated
+    /// as diverging (i.e. as never returning) by the divergence analysis that backs the
+    /// unreachable-code, `NoReturn`-can-return, and missing-`NoReturn` checks.
+    pub extra_diverging_calls: Vec<String>,
+}
@@ -0,0 +1,28 @@
+pub(crate) mod rules;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test_case(Rule::PreferNever, Path::new("RUF_prefer_never.py"))]
+    #[test_case(Rule::NeverUnion, Path::new("RUF020_optional.py"))]
+    #[test_case(Rule::NeverUnion, Path::new("RUF020_nested.py"))]
+    #[test_case(Rule::NeverUnion, Path::new("RUF020_annotated.py"))]
+    #[test_case(Rule::NeverUnion, Path::new("RUF020_bare_none.py"))]
+    fn rules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.noqa_code(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("ruff").join(path).as_path(),
+            &settings::LinterSettings::for_rule(rule_code),
+        )?;
+        assert_messages!(snapshot, diagnostics);
+        Ok(())
+    }
+}
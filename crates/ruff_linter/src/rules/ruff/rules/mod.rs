@@ -0,0 +1,5 @@
+pub(crate) use never_union::{never_union, NeverUnion};
+pub(crate) use prefer_never::{prefer_never, PreferNever};
+
+mod never_union;
+mod prefer_never;
@@ -56,6 +56,9 @@ impl Violation for NeverUnion {
             UnionLike::TypingUnion => {
                 format!("`Union[{never_like}, T]` is equivalent to `T`")
             }
+            UnionLike::Optional => {
+                format!("`Optional[{never_like}]` is equivalent to `None`")
+            }
         }
     }
 
@@ -68,51 +71,55 @@ impl Violation for NeverUnion {
 /// RUF020
 pub(crate) fn never_union(checker: &mut Checker, expr: &Expr) {
     match expr {
-        // Ex) `typing.NoReturn | int`
+        // Ex) `typing.NoReturn | int`, or, nested, `int | (str | Never)`
         Expr::BinOp(ast::ExprBinOp {
             op: Operator::BitOr,
-            left,
-            right,
-            range: _,
+            ..
         }) => {
-            // Analyze the left-hand side of the `|` operator.
-            if let Some(never_like) = NeverLike::from_expr(left, checker.semantic()) {
-                let mut diagnostic = Diagnostic::new(
-                    NeverUnion {
-                        never_like,
-                        union_like: UnionLike::PEP604,
-                    },
-                    left.range(),
-                );
-                // Avoid producing code that would raise an exception when
-                // `Never | None` would be fixed to `None | None`.
-                // Instead do not provide a fix. No action needed for `typing.Union`,
-                // as `Union[None, None]` is valid Python.
-                // See https://github.com/astral-sh/ruff/issues/14567.
-                if !in_union_with_bare_none(checker.semantic()) {
-                    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
-                        checker.locator().slice(right.as_ref()).to_string(),
-                        expr.range(),
-                    )));
-                }
-                checker.diagnostics.push(diagnostic);
+            // Only analyze the outermost union in a chain of `|` expressions: nested
+            // `BinOp`s are visited too, and would otherwise be reported (and "fixed")
+            // once per level of nesting.
+            if is_nested_union(expr, checker.semantic()) {
+                return;
             }
 
-            // Analyze the right-hand side of the `|` operator.
-            if let Some(never_like) = NeverLike::from_expr(right, checker.semantic()) {
+            let members = union_members(expr, checker.semantic());
+            // Avoid producing code that would raise an exception when `None | Never | None`
+            // would be fixed to `None | None`. Check the flattened members being processed
+            // here, not just the ancestors -- the bare `None` may live inside this very
+            // union. See https://github.com/astral-sh/ruff/issues/14567.
+            let avoid_bare_none_fix = members
+                .iter()
+                .any(|member| matches!(member, Expr::NoneLiteral(_)));
+
+            for (index, member) in members.iter().enumerate() {
+                let Some(never_like) = NeverLike::from_expr(member, checker.semantic()) else {
+                    continue;
+                };
+
                 let mut diagnostic = Diagnostic::new(
                     NeverUnion {
                         never_like,
                         union_like: UnionLike::PEP604,
                     },
-                    right.range(),
+                    member.range(),
                 );
-                if !in_union_with_bare_none(checker.semantic()) {
-                    diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
-                        checker.locator().slice(left.as_ref()).to_string(),
-                        expr.range(),
-                    )));
+
+                if !avoid_bare_none_fix {
+                    let rest: Vec<Expr> = members
+                        .iter()
+                        .enumerate()
+                        .filter(|(other_index, _)| *other_index != index)
+                        .map(|(_, member)| (*member).clone())
+                        .collect();
+                    if let Some(replacement) = reconstruct_union(&rest, checker) {
+                        diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+                            replacement,
+                            expr.range(),
+                        )));
+                    }
                 }
+
                 checker.diagnostics.push(diagnostic);
             }
         }
@@ -177,16 +184,88 @@ pub(crate) fn never_union(checker: &mut Checker, expr: &Expr) {
             }
         }
 
+        // Ex) `typing.Optional[typing.NoReturn]`
+        Expr::Subscript(ast::ExprSubscript { value, slice, .. })
+            if checker.semantic().match_typing_expr(value, "Optional") =>
+        {
+            if let Some(never_like) = NeverLike::from_expr(slice, checker.semantic()) {
+                let mut diagnostic = Diagnostic::new(
+                    NeverUnion {
+                        never_like,
+                        union_like: UnionLike::Optional,
+                    },
+                    slice.range(),
+                );
+                // `Optional[NoReturn]` and `Optional[Never]` are equivalent to `None`.
+                diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
+                    "None".to_string(),
+                    expr.range(),
+                )));
+                checker.diagnostics.push(diagnostic);
+            }
+        }
+
         _ => {}
     }
 }
 
+/// Returns `true` if `expr` is itself a member of an enclosing PEP 604 union, i.e. its
+/// immediate parent expression is also a `BinOp` `|`. Used to ensure a nested union like
+/// `int | (str | Never)` is only analyzed once, from its outermost `BinOp`.
+fn is_nested_union(expr: &Expr, semantic: &SemanticModel) -> bool {
+    debug_assert!(matches!(
+        expr,
+        Expr::BinOp(ExprBinOp {
+            op: Operator::BitOr,
+            ..
+        })
+    ));
+    matches!(
+        semantic.current_expressions().nth(1),
+        Some(Expr::BinOp(ExprBinOp {
+            op: Operator::BitOr,
+            ..
+        }))
+    )
+}
+
+/// Collects the leaf members of a PEP 604 union, flattening arbitrarily nested `|`
+/// expressions -- e.g. the leaves of `int | (str | Never)` are `int`, `str`, and `Never`.
+fn union_members<'a>(union: &'a Expr, semantic: &SemanticModel) -> Vec<&'a Expr> {
+    let mut members = Vec::new();
+    traverse_union(&mut |member, _parent| members.push(member), semantic, union);
+    members
+}
+
+/// Reconstructs the minimal union containing `members`, for use as a fix replacing the
+/// entire enclosing union. Returns `None` if `members` is empty. Other `Never`-like members
+/// in `members` are kept as they are.
+fn reconstruct_union(members: &[Expr], checker: &Checker) -> Option<String> {
+    match members {
+        [] => None,
+        [only] => Some(checker.locator().slice(only).to_string()),
+        [first, rest @ ..] => {
+            let union = rest.iter().cloned().fold(first.clone(), |left, right| {
+                Expr::BinOp(ExprBinOp {
+                    left: Box::new(left),
+                    op: Operator::BitOr,
+                    right: Box::new(right),
+                    range: TextRange::default(),
+                })
+            });
+            Some(checker.generator().expr(&union))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum UnionLike {
     /// E.g., `typing.Union[int, str]`
     TypingUnion,
     /// E.g., `int | str`
     PEP604,
+    /// E.g., `typing.Optional[int]`
+    Optional,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -198,7 +277,22 @@ enum NeverLike {
 }
 
 impl NeverLike {
-    fn from_expr(expr: &Expr, semantic: &ruff_python_semantic::SemanticModel) -> Option<Self> {
+    // Note: we don't look through `Literal[...]`, unlike `Annotated[...]` below -- a
+    // `Literal` only ever wraps value literals (ints, strings, bools, enum members, `None`),
+    // so `Literal[NoReturn]` isn't valid `typing` usage in the first place and there's
+    // nothing meaningful to unwrap.
+    fn from_expr(expr: &Expr, semantic: &SemanticModel) -> Option<Self> {
+        // Ex) `Annotated[typing.Never, "metadata"]` -- look through the wrapper to the
+        // wrapped type, since the annotation itself doesn't change the type.
+        if let Expr::Subscript(ast::ExprSubscript { value, slice, .. }) = expr {
+            if semantic.match_typing_expr(value, "Annotated") {
+                if let Expr::Tuple(ast::ExprTuple { elts, .. }) = &**slice {
+                    let wrapped = elts.first()?;
+                    return NeverLike::from_expr(wrapped, semantic);
+                }
+            }
+        }
+
         let qualified_name = semantic.resolve_qualified_name(expr)?;
         if semantic.match_typing_qualified_name(&qualified_name, "NoReturn") {
             Some(NeverLike::NoReturn)
@@ -218,32 +312,3 @@ impl std::fmt::Display for NeverLike {
         }
     }
 }
-
-fn in_union_with_bare_none(semantic: &SemanticModel) -> bool {
-    let mut enclosing_union = None;
-    let mut expression_ancestors = semantic.current_expressions().skip(1);
-    let mut parent_expr = expression_ancestors.next();
-    while let Some(Expr::BinOp(ExprBinOp {
-        op: Operator::BitOr,
-        ..
-    })) = parent_expr
-    {
-        enclosing_union = parent_expr;
-        parent_expr = expression_ancestors.next();
-    }
-
-    let mut is_union_with_bare_none = false;
-    if let Some(enclosing_union) = enclosing_union {
-        traverse_union(
-            &mut |expr, _| {
-                if matches!(expr, Expr::NoneLiteral(_)) {
-                    is_union_with_bare_none = true;
-                }
-            },
-            semantic,
-            enclosing_union,
-        );
-    }
-
-    is_union_with_bare_none
-}
@@ -0,0 +1,102 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_linter::importer::ImportRequest;
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{Expr, Stmt};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for uses of `typing.NoReturn` outside of a function's return annotation.
+///
+/// ## Why is this bad?
+/// `typing.NoReturn` and `typing.Never` are both spellings of the bottom type, but mypy
+/// has standardized on `Never` as the canonical spelling everywhere except a function's
+/// return position, where `NoReturn` remains conventional (and, before Python 3.11, is the
+/// only spelling available from `typing` itself). Using `NoReturn` in a variable annotation, parameter
+/// annotation, type-alias body, or union member is therefore inconsistent with that
+/// convention.
+///
+/// ## Example
+/// ```python
+/// from typing import NoReturn
+///
+///
+/// def f(x: NoReturn) -> None: ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from typing import Never
+///
+///
+/// def f(x: Never) -> None: ...
+/// ```
+///
+/// ## References
+/// - [Python documentation: `typing.Never`](https://docs.python.org/3/library/typing.html#typing.Never)
+/// - [Python documentation: `typing.NoReturn`](https://docs.python.org/3/library/typing.html#typing.NoReturn)
+#[violation]
+pub struct PreferNever;
+
+impl Violation for PreferNever {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`NoReturn` should only be used as a return annotation; use `Never` here instead"
+            .to_string()
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace `NoReturn` with `Never`".to_string())
+    }
+}
+
+/// RUF concerning `NoReturn` outside of a return annotation.
+pub(crate) fn prefer_never(checker: &mut Checker, expr: &Expr) {
+    let semantic = checker.semantic();
+
+    let Some(qualified_name) = semantic.resolve_qualified_name(expr) else {
+        return;
+    };
+    if !semantic.match_typing_qualified_name(&qualified_name, "NoReturn") {
+        return;
+    }
+    if is_function_return_annotation(expr, semantic) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(PreferNever, expr.range());
+
+    let module = if checker.settings().target_version >= ruff_python_ast::PythonVersion::Py311 {
+        "typing"
+    } else {
+        "typing_extensions"
+    };
+    if let Ok((import_edit, binding)) = checker.importer().get_or_import_symbol(
+        &ImportRequest::import_from(module, "Never"),
+        expr.start(),
+        semantic,
+    ) {
+        let never_edit = Edit::range_replacement(binding, expr.range());
+        diagnostic.set_fix(Fix::safe_edits(import_edit, [never_edit]));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Returns `true` if `expr` *is* the return annotation of the immediately enclosing
+/// function, e.g. `def f() -> NoReturn: ...`. A `NoReturn` used as a member of a union
+/// return annotation, e.g. `def f() -> NoReturn | int: ...`, is not exempt -- only a bare
+/// `-> NoReturn` is conventional.
+fn is_function_return_annotation(expr: &Expr, semantic: &SemanticModel) -> bool {
+    let Stmt::FunctionDef(function_def) = semantic.current_statement() else {
+        return false;
+    };
+    function_def
+        .returns
+        .as_deref()
+        .is_some_and(|returns| returns.range() == expr.range())
+}
@@ -0,0 +1,337 @@
+//! Control-flow divergence analysis.
+//!
+//! Mirrors rustc's `diverges` tracking in `rustc_mir_build` (see `thir::cx::block`): a
+//! statement is said to *diverge* when control flow can never fall through to whatever
+//! follows it. We use this to flag statements made unreachable by a preceding `raise`, a
+//! call to a function that never returns, or a loop with no reachable `break`.
+//!
+//! A block (the top-level statements of a `Suite`) diverges if *any* of its statements
+//! diverges -- once a statement diverges, every statement after it is unreachable (and
+//! irrelevant to whether the block as a whole diverges), which is what [`block_diverges`]
+//! checks for. [`find_unreachable`] reports the statements after the first diverging one.
+
+use ruff_python_ast::{self as ast, ExceptHandler, Expr, Stmt};
+
+use crate::SemanticModel;
+
+/// Why a statement diverges, i.e. never falls through to the statement that follows it.
+#[derive(Debug, Clone, Copy)]
+pub enum Divergence {
+    /// A `raise` statement.
+    Raise,
+    /// A call to a function whose return annotation resolves to `NoReturn` or `Never`.
+    NoReturnCall,
+    /// A call to a known-diverging callable, e.g. `sys.exit` or `os._exit`.
+    DivergingCall,
+    /// A `while True:` loop with no reachable `break`.
+    InfiniteLoop,
+    /// A compound statement (`if`/`try`) all of whose branches diverge.
+    AllBranchesDiverge,
+}
+
+impl Divergence {
+    /// A human-readable fragment describing why the statement diverges, suitable for
+    /// splicing into a diagnostic message (e.g. "...as a preceding statement {reason}").
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Divergence::Raise => "always raises",
+            Divergence::NoReturnCall => "always calls a function annotated to never return",
+            Divergence::DivergingCall => "always calls a function that never returns",
+            Divergence::InfiniteLoop => "loops forever",
+            Divergence::AllBranchesDiverge => "diverges on every branch",
+        }
+    }
+}
+
+/// The default set of standard-library callables that are known to never return.
+const DIVERGING_CALLS: &[[&str; 2]] = &[
+    ["sys", "exit"],
+    ["os", "_exit"],
+    ["os", "abort"],
+    ["typing", "assert_never"],
+    ["typing_extensions", "assert_never"],
+];
+
+/// Returns the [`Divergence`] reason if `stmt` unconditionally diverges, or `None` if
+/// control flow can fall through to whatever statement follows it.
+pub fn stmt_diverges(
+    stmt: &Stmt,
+    semantic: &SemanticModel,
+    extra_diverging_calls: &[String],
+) -> Option<Divergence> {
+    match stmt {
+        Stmt::Raise(_) => Some(Divergence::Raise),
+        Stmt::Expr(ast::StmtExpr { value, .. }) => {
+            expr_diverges(value, semantic, extra_diverging_calls)
+        }
+        // The `else` of a `while True:` loop never runs, so it has no bearing here.
+        Stmt::While(ast::StmtWhile { test, body, .. }) => {
+            (is_const_true(test) && !contains_reachable_break(body))
+                .then_some(Divergence::InfiniteLoop)
+        }
+        Stmt::If(ast::StmtIf {
+            body,
+            elif_else_clauses,
+            ..
+        }) => {
+            // An `if` only diverges if it has a final `else` and every branch diverges;
+            // without an `else`, the "fell through without matching" path is reachable.
+            let has_final_else = elif_else_clauses
+                .last()
+                .is_some_and(|clause| clause.test.is_none());
+            if !has_final_else {
+                return None;
+            }
+            let all_diverge = std::iter::once(body.as_slice())
+                .chain(elif_else_clauses.iter().map(|clause| clause.body.as_slice()))
+                .all(|block| block_diverges(block, semantic, extra_diverging_calls));
+            all_diverge.then_some(Divergence::AllBranchesDiverge)
+        }
+        Stmt::Try(ast::StmtTry {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+            ..
+        }) => {
+            // A `finally` that diverges makes the whole `try` diverge, regardless of the
+            // body or handlers.
+            if !finalbody.is_empty()
+                && block_diverges(finalbody, semantic, extra_diverging_calls)
+            {
+                return Some(Divergence::AllBranchesDiverge);
+            }
+            // If there's an `else`, it runs (and must diverge) whenever the `try` body
+            // doesn't raise, so it stands in for the body when checking divergence.
+            let body_diverges = if orelse.is_empty() {
+                block_diverges(body, semantic, extra_diverging_calls)
+            } else {
+                block_diverges(orelse, semantic, extra_diverging_calls)
+            };
+            let handlers_diverge = handlers.iter().all(|handler| {
+                let ExceptHandler::ExceptHandler(handler) = handler;
+                block_diverges(&handler.body, semantic, extra_diverging_calls)
+            });
+            (body_diverges && handlers_diverge).then_some(Divergence::AllBranchesDiverge)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the block as a whole diverges, i.e. if any of its statements
+/// diverges -- everything after the first diverging statement is unreachable (and
+/// irrelevant to whether the block as a whole diverges), so this is equivalent to asking
+/// whether [`find_unreachable`] (or a diverging final statement) would fire.
+pub fn block_diverges(
+    body: &[Stmt],
+    semantic: &SemanticModel,
+    extra_diverging_calls: &[String],
+) -> bool {
+    body.iter()
+        .any(|stmt| stmt_diverges(stmt, semantic, extra_diverging_calls).is_some())
+}
+
+/// Walks `body` (recursing into `if`/`try`/`with`/`for`/`while` bodies, but not into
+/// nested function or class scopes) and returns the first `return <value>` statement or
+/// `yield`/`yield from` expression statement that is reachable, i.e. not preceded by a
+/// diverging sibling statement. A `yield` nested inside another statement (e.g.
+/// `x = yield 1`) is not found.
+pub fn find_reachable_return_value<'a>(
+    body: &'a [Stmt],
+    semantic: &SemanticModel,
+    extra_diverging_calls: &[String],
+) -> Option<&'a Stmt> {
+    for stmt in body {
+        match stmt {
+            Stmt::Return(ast::StmtReturn {
+                value: Some(_), ..
+            }) => return Some(stmt),
+            Stmt::Expr(ast::StmtExpr { value, .. })
+                if matches!(value.as_ref(), Expr::Yield(_) | Expr::YieldFrom(_)) =>
+            {
+                return Some(stmt);
+            }
+            Stmt::If(ast::StmtIf {
+                body,
+                elif_else_clauses,
+                ..
+            }) => {
+                for block in std::iter::once(body.as_slice())
+                    .chain(elif_else_clauses.iter().map(|clause| clause.body.as_slice()))
+                {
+                    if let Some(found) =
+                        find_reachable_return_value(block, semantic, extra_diverging_calls)
+                    {
+                        return Some(found);
+                    }
+                }
+            }
+            Stmt::Try(ast::StmtTry {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+                ..
+            }) => {
+                let handler_blocks = handlers.iter().map(|handler| {
+                    let ExceptHandler::ExceptHandler(handler) = handler;
+                    handler.body.as_slice()
+                });
+                for block in std::iter::once(body.as_slice())
+                    .chain(handler_blocks)
+                    .chain(std::iter::once(orelse.as_slice()))
+                    .chain(std::iter::once(finalbody.as_slice()))
+                {
+                    if let Some(found) =
+                        find_reachable_return_value(block, semantic, extra_diverging_calls)
+                    {
+                        return Some(found);
+                    }
+                }
+            }
+            Stmt::With(ast::StmtWith { body, .. }) => {
+                if let Some(found) =
+                    find_reachable_return_value(body, semantic, extra_diverging_calls)
+                {
+                    return Some(found);
+                }
+            }
+            Stmt::For(ast::StmtFor { body, orelse, .. })
+            | Stmt::While(ast::StmtWhile { body, orelse, .. }) => {
+                // The `orelse` runs whenever the loop completes without hitting a `break`,
+                // so a `return`/`yield` reachable only through it is just as reachable as
+                // one in the loop body itself.
+                for block in [body.as_slice(), orelse.as_slice()] {
+                    if let Some(found) =
+                        find_reachable_return_value(block, semantic, extra_diverging_calls)
+                    {
+                        return Some(found);
+                    }
+                }
+            }
+            // Nested function and class definitions introduce their own scope: a
+            // `return`/`yield` inside one doesn't belong to the function we're analyzing.
+            _ => {}
+        }
+
+        if stmt_diverges(stmt, semantic, extra_diverging_calls).is_some() {
+            break;
+        }
+    }
+    None
+}
+
+/// Walks the top-level statements of `body` and returns the first diverging statement
+/// along with the (non-empty) slice of siblings it renders unreachable.
+pub fn find_unreachable<'a>(
+    body: &'a [Stmt],
+    semantic: &SemanticModel,
+    extra_diverging_calls: &[String],
+) -> Option<(&'a Stmt, Divergence, &'a [Stmt])> {
+    for (index, stmt) in body.iter().enumerate() {
+        if let Some(divergence) = stmt_diverges(stmt, semantic, extra_diverging_calls) {
+            let rest = &body[index + 1..];
+            if rest.is_empty() {
+                return None;
+            }
+            return Some((stmt, divergence, rest));
+        }
+    }
+    None
+}
+
+fn expr_diverges(
+    expr: &Expr,
+    semantic: &SemanticModel,
+    extra_diverging_calls: &[String],
+) -> Option<Divergence> {
+    let Expr::Call(ast::ExprCall { func, .. }) = expr else {
+        return None;
+    };
+    let qualified_name = semantic.resolve_qualified_name(func)?;
+
+    if DIVERGING_CALLS
+        .iter()
+        .any(|segments| qualified_name.segments() == segments)
+        || extra_diverging_calls
+            .iter()
+            .any(|name| qualified_name.to_string() == *name)
+    {
+        return Some(Divergence::DivergingCall);
+    }
+
+    return_annotation_is_never(func, semantic).then_some(Divergence::NoReturnCall)
+}
+
+/// Returns `true` if `func` is a bare name bound to a function whose return annotation is
+/// `typing.NoReturn` or `typing.Never`. Attribute calls (e.g. `self.fail()`) are not
+/// resolved.
+fn return_annotation_is_never(func: &Expr, semantic: &SemanticModel) -> bool {
+    let Expr::Name(ast::ExprName { id, .. }) = func else {
+        return false;
+    };
+    let Some(binding_id) = semantic.lookup_symbol(id.as_str()) else {
+        return false;
+    };
+    let Some(Stmt::FunctionDef(ast::StmtFunctionDef {
+        returns: Some(returns),
+        ..
+    })) = semantic.binding(binding_id).statement(semantic)
+    else {
+        return false;
+    };
+    let Some(qualified_name) = semantic.resolve_qualified_name(returns) else {
+        return false;
+    };
+    semantic.match_typing_qualified_name(&qualified_name, "NoReturn")
+        || semantic.match_typing_qualified_name(&qualified_name, "Never")
+}
+
+fn is_const_true(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::BooleanLiteral(ast::ExprBooleanLiteral { value: true, .. })
+    )
+}
+
+/// Returns `true` if `body` contains a `break` that would escape the loop it's nested in
+/// (i.e. not one swallowed by a nested `for`/`while`/function definition).
+fn contains_reachable_break(body: &[Stmt]) -> bool {
+    body.iter().any(|stmt| match stmt {
+        Stmt::Break(_) => true,
+        Stmt::If(ast::StmtIf {
+            body,
+            elif_else_clauses,
+            ..
+        }) => {
+            contains_reachable_break(body)
+                || elif_else_clauses
+                    .iter()
+                    .any(|clause| contains_reachable_break(&clause.body))
+        }
+        Stmt::Try(ast::StmtTry {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+            ..
+        }) => {
+            contains_reachable_break(body)
+                || handlers.iter().any(|handler| {
+                    let ExceptHandler::ExceptHandler(handler) = handler;
+                    contains_reachable_break(&handler.body)
+                })
+                || contains_reachable_break(orelse)
+                || contains_reachable_break(finalbody)
+        }
+        Stmt::With(ast::StmtWith { body, .. }) => contains_reachable_break(body),
+        Stmt::Match(ast::StmtMatch { cases, .. }) => cases
+            .iter()
+            .any(|case| contains_reachable_break(&case.body)),
+        // `for` and `while` arms introduce their own loop scope, so a `break` nested
+        // inside them doesn't escape to the loop we're analyzing. `match` is not a loop
+        // and is handled above: a `break` inside a `case` body escapes just like one
+        // inside an `if`/`try`.
+        _ => false,
+    })
+}